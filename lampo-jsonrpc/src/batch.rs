@@ -0,0 +1,132 @@
+//! JSON-RPC 2.0 batch request/response handling.
+
+use serde_json::Value;
+
+use crate::errors::{Error, ErrorCode, RpcError};
+use crate::message::Request;
+pub use crate::message::Response;
+
+/// One element of a parsed batch request.
+#[derive(Debug)]
+pub enum BatchItem {
+    /// A well-formed request awaiting a response.
+    Request(Request),
+    /// A notification: well-formed apart from lacking an `id`, so the
+    /// spec forbids responding to it at all.
+    Notification,
+    /// An element that couldn't be parsed as a request.
+    Invalid(RpcError),
+}
+
+/// A parsed JSON-RPC 2.0 batch request.
+#[derive(Debug)]
+pub struct BatchRequest(Vec<BatchItem>);
+
+impl BatchRequest {
+    /// Parse a batch body (a JSON array of request objects). An empty
+    /// array, or a batch where every element is invalid, is reported as
+    /// a single top-level error.
+    pub fn parse(s: &str) -> Result<BatchRequest, Error> {
+        let values: Vec<Value> = serde_json::from_str(s)?;
+        if values.is_empty() {
+            return Err(invalid_batch_error());
+        }
+
+        let items: Vec<BatchItem> = values
+            .into_iter()
+            .map(|value| {
+                let is_notification_shape = value.is_object()
+                    && value.get("id").is_none()
+                    && value.get("jsonrpc").and_then(Value::as_str) == Some("2.0")
+                    && value.get("method").is_some_and(Value::is_string);
+                match Request::from_value(value) {
+                    Ok(request) => BatchItem::Request(request),
+                    Err(_) if is_notification_shape => BatchItem::Notification,
+                    Err(e) => BatchItem::Invalid(RpcError::from(e)),
+                }
+            })
+            .collect();
+
+        if items
+            .iter()
+            .all(|item| matches!(item, BatchItem::Invalid(_)))
+        {
+            return Err(invalid_batch_error());
+        }
+
+        Ok(BatchRequest(items))
+    }
+
+    /// The parsed elements, in the order they appeared in the batch.
+    pub fn items(&self) -> &[BatchItem] {
+        &self.0
+    }
+}
+
+/// The responses to send back for a batch, gathered in completion order.
+#[derive(Debug)]
+pub struct BatchResponse(Vec<Response>);
+
+impl BatchResponse {
+    /// Assemble a batch response, or `None` if there's nothing to send
+    /// back (a batch made up entirely of notifications).
+    pub fn new(responses: Vec<Response>) -> Option<BatchResponse> {
+        if responses.is_empty() {
+            None
+        } else {
+            Some(BatchResponse(responses))
+        }
+    }
+
+    /// The responses to send back, in arbitrary (completion) order.
+    pub fn into_inner(self) -> Vec<Response> {
+        self.0
+    }
+}
+
+fn invalid_batch_error() -> Error {
+    Error::Rpc(RpcError {
+        code: ErrorCode::InvalidRequest,
+        message: ErrorCode::InvalidRequest.message().to_owned(),
+        data: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_batch_is_invalid_request() {
+        let err = BatchRequest::parse("[]").unwrap_err();
+        assert!(matches!(err, Error::Rpc(ref e) if e.code == ErrorCode::InvalidRequest));
+    }
+
+    #[test]
+    fn all_junk_batch_is_invalid_request() {
+        let err = BatchRequest::parse("[1,2,3]").unwrap_err();
+        assert!(matches!(err, Error::Rpc(ref e) if e.code == ErrorCode::InvalidRequest));
+    }
+
+    #[test]
+    fn mixed_batch_classifies_each_item() {
+        let batch = BatchRequest::parse(
+            r#"[
+                {"jsonrpc":"2.0","id":1,"method":"ping"},
+                {"jsonrpc":"2.0","method":"notify_only"},
+                42
+            ]"#,
+        )
+        .unwrap();
+        let items = batch.items();
+        assert_eq!(items.len(), 3);
+        assert!(matches!(items[0], BatchItem::Request(_)));
+        assert!(matches!(items[1], BatchItem::Notification));
+        assert!(matches!(items[2], BatchItem::Invalid(_)));
+    }
+
+    #[test]
+    fn batch_response_omits_when_empty() {
+        assert!(BatchResponse::new(Vec::new()).is_none());
+    }
+}
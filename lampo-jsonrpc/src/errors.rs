@@ -40,8 +40,25 @@ impl From<RpcError> for Error {
 
 impl From<anyhow::Error> for Error {
     fn from(e: anyhow::Error) -> Error {
+        // Recover a structured error if one is buried in the anyhow chain,
+        // rather than flattening everything into a bare -1 message.
+        let e = match e.downcast::<RpcError>() {
+            Ok(rpc) => return Error::Rpc(rpc),
+            Err(e) => e,
+        };
+        let e = match e.downcast::<Error>() {
+            Ok(err) => return err,
+            Err(e) => e,
+        };
+        if let Some(json_err) = e.downcast_ref::<serde_json::Error>() {
+            return Error::Rpc(RpcError {
+                code: ErrorCode::ParseError,
+                message: format!("{json_err}"),
+                data: None,
+            });
+        }
         Error::Rpc(RpcError {
-            code: -1,
+            code: ErrorCode::InternalError,
             message: format!("{e}"),
             data: None,
         })
@@ -70,27 +87,191 @@ impl error::Error for Error {
     }
 }
 
+/// A JSONRPCv2.0 spec error code.
+///
+/// Covers the codes reserved by the spec (`-32700..-32600`), the
+/// implementation-defined server-error range (`-32000..-32099`), and
+/// anything else an application wants to return.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// Invalid JSON was received by the server
+    ParseError,
+    /// The JSON sent is not a valid Request object
+    InvalidRequest,
+    /// The method does not exist / is not available
+    MethodNotFound,
+    /// Invalid method parameter(s)
+    InvalidParams,
+    /// Internal JSON-RPC error
+    InternalError,
+    /// Reserved for implementation-defined server errors (-32000..-32099)
+    ServerError(i64),
+    /// Any other error code, including reserved codes without a named variant
+    Other(i32),
+}
+
+impl ErrorCode {
+    /// The integer code of this error, as defined by the spec.
+    pub fn code(&self) -> i32 {
+        match *self {
+            ErrorCode::ParseError => -32700,
+            ErrorCode::InvalidRequest => -32600,
+            ErrorCode::MethodNotFound => -32601,
+            ErrorCode::InvalidParams => -32602,
+            ErrorCode::InternalError => -32603,
+            ErrorCode::ServerError(code) => code as i32,
+            ErrorCode::Other(code) => code,
+        }
+    }
+
+    /// The canonical, spec-defined message for this error code.
+    pub fn message(&self) -> &'static str {
+        match *self {
+            ErrorCode::ParseError => "Parse error",
+            ErrorCode::InvalidRequest => "Invalid Request",
+            ErrorCode::MethodNotFound => "Method not found",
+            ErrorCode::InvalidParams => "Invalid params",
+            ErrorCode::InternalError => "Internal error",
+            ErrorCode::ServerError(_) => "Server error",
+            ErrorCode::Other(_) => "Other error",
+        }
+    }
+}
+
+impl From<i32> for ErrorCode {
+    fn from(code: i32) -> ErrorCode {
+        match code {
+            -32700 => ErrorCode::ParseError,
+            -32600 => ErrorCode::InvalidRequest,
+            -32601 => ErrorCode::MethodNotFound,
+            -32602 => ErrorCode::InvalidParams,
+            -32603 => ErrorCode::InternalError,
+            -32099..=-32000 => ErrorCode::ServerError(code as i64),
+            _ => ErrorCode::Other(code),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let code = i32::deserialize(deserializer)?;
+        Ok(ErrorCode::from(code))
+    }
+}
+
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 /// A JSONRPCv2.0 spec compilant error object
 pub struct RpcError {
     /// The integer identifier of the error
-    pub code: i32,
+    pub code: ErrorCode,
     /// A string describing the error message
     pub message: String,
     /// Additional data specific to the error
     pub data: Option<serde_json::Value>,
 }
 
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
 impl From<Error> for RpcError {
     fn from(value: Error) -> Self {
         match value {
             Error::Rpc(rpc) => rpc.clone(),
-            _ => RpcError {
-                code: -1,
+            Error::Json(_) => RpcError {
+                code: ErrorCode::ParseError,
+                message: format!("{value}"),
+                data: None,
+            },
+            Error::NonceMismatch | Error::VersionMismatch => RpcError {
+                code: ErrorCode::InvalidRequest,
+                message: format!("{value}"),
+                data: None,
+            },
+            Error::NoErrorOrResult | Error::Io(_) => RpcError {
+                code: ErrorCode::InternalError,
                 message: format!("{value}"),
                 data: None,
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_codes_round_trip() {
+        for code in [-32700, -32600, -32601, -32602, -32603] {
+            assert_eq!(ErrorCode::from(code).code(), code);
+        }
+    }
+
+    #[test]
+    fn server_error_range_boundaries() {
+        assert_eq!(ErrorCode::from(-32000), ErrorCode::ServerError(-32000));
+        assert_eq!(ErrorCode::from(-32099), ErrorCode::ServerError(-32099));
+    }
+
+    #[test]
+    fn outside_server_error_range_is_other() {
+        assert_eq!(ErrorCode::from(-32100), ErrorCode::Other(-32100));
+        assert_eq!(ErrorCode::from(-31999), ErrorCode::Other(-31999));
+    }
+
+    #[test]
+    fn anyhow_conversion_recovers_buried_rpc_error() {
+        let rpc = RpcError {
+            code: ErrorCode::InvalidParams,
+            message: "bad params".to_owned(),
+            data: Some(serde_json::json!({ "param": "amount" })),
+        };
+        let anyhow_err = anyhow::Error::msg(rpc.clone());
+        let err: Error = anyhow_err.into();
+        assert!(matches!(err, Error::Rpc(ref e) if *e == rpc));
+    }
+
+    #[test]
+    fn anyhow_conversion_recovers_buried_error() {
+        let anyhow_err: anyhow::Error = Error::NonceMismatch.into();
+        let err: Error = anyhow_err.into();
+        assert!(matches!(err, Error::NonceMismatch));
+    }
+
+    #[test]
+    fn anyhow_conversion_maps_buried_json_error_to_parse_error() {
+        let json_err = serde_json::from_str::<serde_json::Value>("not json").unwrap_err();
+        let anyhow_err: anyhow::Error = json_err.into();
+        let err: Error = anyhow_err.into();
+        assert!(matches!(err, Error::Rpc(ref e) if e.code == ErrorCode::ParseError));
+    }
+
+    #[test]
+    fn anyhow_conversion_falls_back_to_internal_error() {
+        let anyhow_err = anyhow::anyhow!("something unrelated went wrong");
+        let err: Error = anyhow_err.into();
+        assert!(matches!(err, Error::Rpc(ref e) if e.code == ErrorCode::InternalError));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod batch;
+pub mod errors;
+pub mod message;
+pub mod subscription;
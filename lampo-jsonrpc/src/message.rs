@@ -0,0 +1,303 @@
+//! Typed JSON-RPC 2.0 message layer: `Request`/`Response` objects and
+//! their `Version`/`Id` wire types.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::{Error, RpcError};
+
+/// The JSON-RPC protocol version. The only value the spec allows on the
+/// wire is the string `"2.0"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Version {
+    #[default]
+    V2,
+}
+
+impl Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str("2.0")
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(ref s) if s == "2.0" => Ok(Version::V2),
+            other => Err(serde::de::Error::custom(format!(
+                "`jsonrpc` field set to non-\"2.0\" value: {other}"
+            ))),
+        }
+    }
+}
+
+/// A JSON-RPC request or response identifier.
+///
+/// The spec allows strings, numbers, or null; this crate never sends or
+/// expects a `null` id, so `Id` is restricted to the two useful cases.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Id(IdRepr);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum IdRepr {
+    Number(i64),
+    String(String),
+}
+
+impl From<i64> for Id {
+    fn from(n: i64) -> Self {
+        Id(IdRepr::Number(n))
+    }
+}
+
+impl From<String> for Id {
+    fn from(s: String) -> Self {
+        Id(IdRepr::String(s))
+    }
+}
+
+impl From<&str> for Id {
+    fn from(s: &str) -> Self {
+        Id(IdRepr::String(s.to_owned()))
+    }
+}
+
+/// A JSON-RPC 2.0 request object.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Request {
+    pub jsonrpc: Version,
+    pub id: Id,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+/// The wire representation of a [`Request`], before its `jsonrpc` field
+/// has been checked.
+#[derive(Deserialize)]
+struct RequestWire {
+    #[serde(default)]
+    jsonrpc: Option<Value>,
+    id: Id,
+    method: String,
+    #[serde(default)]
+    params: Option<Value>,
+}
+
+impl Request {
+    /// Build a request with the current protocol version.
+    pub fn new(id: impl Into<Id>, method: impl Into<String>, params: Option<Value>) -> Self {
+        Request {
+            jsonrpc: Version::V2,
+            id: id.into(),
+            method: method.into(),
+            params,
+        }
+    }
+
+    /// Parse a request, rejecting anything whose `jsonrpc` field is
+    /// missing, the wrong type, or not exactly `"2.0"`.
+    pub fn parse(s: &str) -> Result<Request, Error> {
+        let wire: RequestWire = serde_json::from_str(s)?;
+        Request::from_wire(wire)
+    }
+
+    /// As [`Request::parse`], but from an already-parsed JSON value (used
+    /// to classify individual elements of a batch).
+    pub(crate) fn from_value(value: Value) -> Result<Request, Error> {
+        let wire: RequestWire = serde_json::from_value(value)?;
+        Request::from_wire(wire)
+    }
+
+    fn from_wire(wire: RequestWire) -> Result<Request, Error> {
+        check_version(&wire.jsonrpc)?;
+        Ok(Request {
+            jsonrpc: Version::V2,
+            id: wire.id,
+            method: wire.method,
+            params: wire.params,
+        })
+    }
+}
+
+/// The wire representation used when serializing a [`Response`].
+#[derive(Serialize)]
+struct RawResponse {
+    jsonrpc: Version,
+    id: Id,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+/// The wire representation of a [`Response`], before its `jsonrpc` field
+/// and exactly-one-of-`result`/`error` invariant have been checked.
+#[derive(Deserialize)]
+struct ResponseWire {
+    #[serde(default)]
+    jsonrpc: Option<Value>,
+    id: Id,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<RpcError>,
+}
+
+/// Deserialize a field that is present on the wire as `Some`, even when its
+/// value is `null`; a missing field is `None` through `#[serde(default)]`.
+fn deserialize_present<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
+}
+
+/// A JSON-RPC 2.0 response object, validated to carry exactly one of
+/// `result` or `error`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub jsonrpc: Version,
+    pub id: Id,
+    pub result: Option<Value>,
+    pub error: Option<RpcError>,
+}
+
+impl Response {
+    /// Build a successful response.
+    pub fn ok(id: impl Into<Id>, result: Value) -> Self {
+        Response {
+            jsonrpc: Version::V2,
+            id: id.into(),
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Build an error response.
+    pub fn err(id: impl Into<Id>, error: RpcError) -> Self {
+        Response {
+            jsonrpc: Version::V2,
+            id: id.into(),
+            result: None,
+            error: Some(error),
+        }
+    }
+
+    /// Parse a response, rejecting a bad `jsonrpc` field the same way
+    /// [`Request::parse`] does, and enforcing that exactly one of
+    /// `result`/`error` is present.
+    pub fn parse(s: &str) -> Result<Response, Error> {
+        let wire: ResponseWire = serde_json::from_str(s)?;
+        check_version(&wire.jsonrpc)?;
+        match (&wire.result, &wire.error) {
+            (Some(_), None) | (None, Some(_)) => Ok(Response {
+                jsonrpc: Version::V2,
+                id: wire.id,
+                result: wire.result,
+                error: wire.error,
+            }),
+            _ => Err(Error::NoErrorOrResult),
+        }
+    }
+
+    /// Parse a response and check that its `id` matches the id of the
+    /// request it is meant to answer.
+    pub fn parse_matching(s: &str, expected_id: &Id) -> Result<Response, Error> {
+        let response = Self::parse(s)?;
+        if &response.id != expected_id {
+            return Err(Error::NonceMismatch);
+        }
+        Ok(response)
+    }
+}
+
+impl Serialize for Response {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RawResponse {
+            jsonrpc: self.jsonrpc,
+            id: self.id.clone(),
+            result: self.result.clone(),
+            error: self.error.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Check that a wire `jsonrpc` field is present and is exactly the string
+/// `"2.0"`, catching a missing field, the wrong JSON type, and the wrong
+/// string value alike.
+fn check_version(value: &Option<Value>) -> Result<(), Error> {
+    match value {
+        Some(Value::String(s)) if s == "2.0" => Ok(()),
+        _ => Err(Error::VersionMismatch),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_non_2_0_version() {
+        let err = Request::parse(r#"{"jsonrpc":"1.0","id":1,"method":"ping"}"#).unwrap_err();
+        assert!(matches!(err, Error::VersionMismatch));
+    }
+
+    #[test]
+    fn rejects_missing_version() {
+        let err = Request::parse(r#"{"id":1,"method":"ping"}"#).unwrap_err();
+        assert!(matches!(err, Error::VersionMismatch));
+    }
+
+    #[test]
+    fn rejects_non_string_version() {
+        let err = Request::parse(r#"{"jsonrpc":2.0,"id":1,"method":"ping"}"#).unwrap_err();
+        assert!(matches!(err, Error::VersionMismatch));
+
+        let err = Request::parse(r#"{"jsonrpc":null,"id":1,"method":"ping"}"#).unwrap_err();
+        assert!(matches!(err, Error::VersionMismatch));
+    }
+
+    #[test]
+    fn rejects_response_with_neither_result_nor_error() {
+        let err = Response::parse(r#"{"jsonrpc":"2.0","id":1}"#).unwrap_err();
+        assert!(matches!(err, Error::NoErrorOrResult));
+    }
+
+    #[test]
+    fn rejects_response_with_both_result_and_error() {
+        let err = Response::parse(
+            r#"{"jsonrpc":"2.0","id":1,"result":1,"error":{"code":-32000,"message":"x"}}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::NoErrorOrResult));
+    }
+
+    #[test]
+    fn rejects_mismatched_id() {
+        let expected: Id = 1.into();
+        let err = Response::parse_matching(r#"{"jsonrpc":"2.0","id":2,"result":1}"#, &expected)
+            .unwrap_err();
+        assert!(matches!(err, Error::NonceMismatch));
+    }
+
+    #[test]
+    fn accepts_matching_id() {
+        let expected: Id = 1.into();
+        let response = Response::parse_matching(r#"{"jsonrpc":"2.0","id":1,"result":1}"#, &expected)
+            .unwrap();
+        assert_eq!(response.id, expected);
+    }
+}
@@ -0,0 +1,111 @@
+//! Subscription (pubsub) support: server-initiated notifications and the
+//! errors specific to managing subscriptions.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::errors::{ErrorCode, RpcError};
+use crate::message::Version;
+
+/// The implementation-defined server-error code for "subscription not
+/// found".
+const SUBSCRIPTION_NOT_FOUND: i64 = -32000;
+/// The implementation-defined server-error code for "too many
+/// subscriptions".
+const TOO_MANY_SUBSCRIPTIONS: i64 = -32001;
+
+/// Identifies a subscription created by a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubscriptionID(u32);
+
+impl SubscriptionID {
+    pub fn new(id: u32) -> Self {
+        SubscriptionID(id)
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for SubscriptionID {
+    fn from(id: u32) -> Self {
+        SubscriptionID(id)
+    }
+}
+
+/// A server-initiated message: a `method` and `params` like
+/// [`crate::message::Request`], but no `id`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    pub jsonrpc: Version,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl Notification {
+    /// Build a notification for the given subscription event.
+    pub fn new(method: impl Into<String>, params: Option<Value>) -> Self {
+        Notification {
+            jsonrpc: Version::V2,
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+impl RpcError {
+    /// The error returned when a client references a subscription id the
+    /// server doesn't know about.
+    pub fn subscription_not_found(id: SubscriptionID) -> RpcError {
+        RpcError {
+            code: ErrorCode::ServerError(SUBSCRIPTION_NOT_FOUND),
+            message: "Subscription not found".to_owned(),
+            data: Some(subscription_data(id)),
+        }
+    }
+
+    /// The error returned when a client tries to open more subscriptions
+    /// than the server allows.
+    pub fn too_many_subscriptions(id: SubscriptionID) -> RpcError {
+        RpcError {
+            code: ErrorCode::ServerError(TOO_MANY_SUBSCRIPTIONS),
+            message: "Too many subscriptions".to_owned(),
+            data: Some(subscription_data(id)),
+        }
+    }
+}
+
+fn subscription_data(id: SubscriptionID) -> Value {
+    json!({ "subscription": id.as_u32() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscription_not_found_embeds_id_in_server_error_range() {
+        let err = RpcError::subscription_not_found(SubscriptionID::new(7));
+        assert_eq!(err.code, ErrorCode::ServerError(SUBSCRIPTION_NOT_FOUND));
+        assert_eq!(err.data, Some(json!({ "subscription": 7 })));
+    }
+
+    #[test]
+    fn too_many_subscriptions_embeds_id_in_server_error_range() {
+        let err = RpcError::too_many_subscriptions(SubscriptionID::new(3));
+        assert_eq!(err.code, ErrorCode::ServerError(TOO_MANY_SUBSCRIPTIONS));
+        assert_eq!(err.data, Some(json!({ "subscription": 3 })));
+    }
+
+    #[test]
+    fn notification_round_trips_without_an_id() {
+        let notification = Notification::new("channel_opened", Some(json!({ "amount": 1 })));
+        let serialized = serde_json::to_value(&notification).unwrap();
+        assert!(serialized.get("id").is_none());
+        let deserialized: Notification = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, notification);
+    }
+}